@@ -1,71 +1,201 @@
-use std::collections::HashMap;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+/// One variant per reserved word, so the parser can match keywords
+/// exhaustively instead of re-comparing the lexeme string.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Kw {
+    Hindsa,    // int
+    Asharia,   // float
+    Agar,      // if
+    Phir,      // else
+    Lekinagar, // else if
+    Jabtk,     // while
+    Niklo,     // break
+    Wapsi,     // return
+    Irshaad,   // print
+    Chalooo,   // continue
+}
+
+/// Static lookup table replacing the old `HashMap`-backed keyword set.
+fn lookup_keyword(word: &str) -> Option<Kw> {
+    match word {
+        "hindsa" => Some(Kw::Hindsa),
+        "asharia" => Some(Kw::Asharia),
+        "agar" => Some(Kw::Agar),
+        "phir" => Some(Kw::Phir),
+        "lekinagar" => Some(Kw::Lekinagar),
+        "jabtk" => Some(Kw::Jabtk),
+        "niklo" => Some(Kw::Niklo),
+        "wapsi" => Some(Kw::Wapsi),
+        "irshaad" => Some(Kw::Irshaad),
+        "chalooo" => Some(Kw::Chalooo),
+        _ => None,
+    }
+}
 
 #[derive(Debug, PartialEq, Clone)]
 enum TokenType {
-    Keyword,
+    Keyword(Kw),
     Identifier,
     IntegerLiteral,
     FloatLiteral,
-    Operator,
-    Punctuator,
+    HexLiteral,
+    BinLiteral,
+    OctLiteral,
+    StringLiteral,
+    DocComment,
+    Plus,
+    PlusEq,
+    Minus,
+    MinusEq,
+    Star,
+    StarEq,
+    Slash,
+    SlashEq,
+    Eq,
+    EqEq,
+    Bang,
+    BangEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Semicolon,
     Unknown,
 }
 
+/// Maps a single-character operator and whether it was followed by `=`
+/// to its concrete `TokenType` variant.
+fn operator_token_type(c: char, compound: bool) -> TokenType {
+    match (c, compound) {
+        ('+', false) => TokenType::Plus,
+        ('+', true) => TokenType::PlusEq,
+        ('-', false) => TokenType::Minus,
+        ('-', true) => TokenType::MinusEq,
+        ('*', false) => TokenType::Star,
+        ('*', true) => TokenType::StarEq,
+        ('/', false) => TokenType::Slash,
+        ('/', true) => TokenType::SlashEq,
+        ('=', false) => TokenType::Eq,
+        ('=', true) => TokenType::EqEq,
+        ('!', false) => TokenType::Bang,
+        ('!', true) => TokenType::BangEq,
+        ('<', false) => TokenType::Lt,
+        ('<', true) => TokenType::LtEq,
+        ('>', false) => TokenType::Gt,
+        ('>', true) => TokenType::GtEq,
+        (other, _) => unreachable!("operator_token_type called with non-operator '{other}'"),
+    }
+}
+
+/// Maps a punctuator character to its concrete `TokenType` variant.
+fn punctuator_token_type(c: char) -> TokenType {
+    match c {
+        '(' => TokenType::LParen,
+        ')' => TokenType::RParen,
+        '{' => TokenType::LBrace,
+        '}' => TokenType::RBrace,
+        ';' => TokenType::Semicolon,
+        other => unreachable!("punctuator_token_type called with non-punctuator '{other}'"),
+    }
+}
+
+/// The byte range and human-readable position of a token or diagnostic
+/// within the source text.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// A lexing problem detected while scanning, reported alongside the
+/// token stream instead of being swallowed into an `Unknown` token.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Message {
+    UnexpectedCharacter(char),
+    InvalidCharacter { found: char, expected: char },
+    UnclosedStringLiteral,
+    MalformedNumericLiteral { literal: String },
+    UnterminatedBlockComment,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagnostic {
+    pub message: Message,
+    pub span: Span,
+}
+
+/// Accumulates diagnostics raised while lexing so callers can render a
+/// full "line:col" error report instead of discovering problems one
+/// `Unknown` token at a time.
+#[derive(Debug, Default)]
+pub struct Logger {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Logger {
+    pub fn new() -> Self {
+        Logger {
+            diagnostics: Vec::new(),
+        }
+    }
+
+    pub fn report(&mut self, message: Message, span: Span) {
+        self.diagnostics.push(Diagnostic { message, span });
+    }
+
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
+
 #[derive(Debug)]
 pub struct Token {
     token_type: TokenType,
     value: String,
-    line: usize,
+    span: Span,
 }
 
 impl Token {
-    pub fn new(token_type: TokenType, value: String, line: usize) -> Self {
+    pub fn new(token_type: TokenType, value: String, span: Span) -> Self {
         Token {
             token_type,
             value,
-            line,
+            span,
         }
     }
 }
 
-pub struct LexicalAnalyzer {
-    input: String,
+pub struct LexicalAnalyzer<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
     position: usize,
     line: usize,
-    keywords: HashMap<String, TokenType>,
+    column: usize,
+    logger: Logger,
 }
 
-impl LexicalAnalyzer {
-    pub fn new(source: String) -> Self {
-        let mut analyzer = LexicalAnalyzer {
+impl<'a> LexicalAnalyzer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        LexicalAnalyzer {
             input: source,
+            chars: source.char_indices().peekable(),
             position: 0,
             line: 1,
-            keywords: HashMap::new(),
-        };
-        analyzer.init_keywords();
-        analyzer
-    }
-
-    fn init_keywords(&mut self) {
-        self.keywords
-            .insert("hindsa".to_string(), TokenType::Keyword); // int
-        self.keywords
-            .insert("asharia".to_string(), TokenType::Keyword); // float
-        self.keywords.insert("agar".to_string(), TokenType::Keyword); // if
-        self.keywords.insert("phir".to_string(), TokenType::Keyword); // else
-        self.keywords
-            .insert("lekinagar".to_string(), TokenType::Keyword); // else if
-        self.keywords
-            .insert("jabtk".to_string(), TokenType::Keyword); // while
-        self.keywords
-            .insert("niklo".to_string(), TokenType::Keyword); // break
-        self.keywords
-            .insert("wapsi".to_string(), TokenType::Keyword); // return
-        self.keywords
-            .insert("irshaad".to_string(), TokenType::Keyword); // print
-        self.keywords
-            .insert("chalooo".to_string(), TokenType::Keyword); // continue
+            column: 1,
+            logger: Logger::new(),
+        }
     }
 
     fn is_whitespace(c: char) -> bool {
@@ -84,124 +214,385 @@ impl LexicalAnalyzer {
         LexicalAnalyzer::is_alpha(c) || LexicalAnalyzer::is_digit(c)
     }
 
+    /// Returns the next character without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    /// Consumes and returns the next character, advancing the byte
+    /// position and tracking the line/column as it goes.
+    fn bump(&mut self) -> Option<char> {
+        let (_, c) = self.chars.next()?;
+        self.position += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    /// Builds the span covering everything consumed since `(start, start_line, start_column)`.
+    fn span_from(&self, start: usize, start_line: usize, start_column: usize) -> Span {
+        Span {
+            start,
+            end: self.position,
+            line: start_line,
+            column: start_column,
+        }
+    }
+
     fn get_next_word(&mut self) -> String {
         let start = self.position;
-        while self.position < self.input.len()
-            && LexicalAnalyzer::is_alphanum(self.input.chars().nth(self.position).unwrap())
-        {
-            self.position += 1;
+        while let Some(c) = self.peek() {
+            if !LexicalAnalyzer::is_alphanum(c) {
+                break;
+            }
+            self.bump();
         }
         self.input[start..self.position].to_string()
     }
 
-    fn get_next_number(&mut self) -> String {
-        let start = self.position;
-        let mut has_decimal = false;
+    /// Scans the digit run of a radix-prefixed literal (`0x`/`0b`/`0o`),
+    /// having already consumed the prefix. Reports a `MalformedNumericLiteral`
+    /// diagnostic if no valid digits follow the prefix.
+    fn finish_radix_literal(
+        &mut self,
+        start: usize,
+        start_line: usize,
+        start_column: usize,
+        is_valid_digit: fn(char) -> bool,
+    ) -> String {
+        let digits_start = self.position;
+        while let Some(c) = self.peek() {
+            if is_valid_digit(c) {
+                self.bump();
+            } else {
+                break;
+            }
+        }
 
-        while self.position < self.input.len() {
-            let current_char = self.input.chars().nth(self.position).unwrap();
+        let literal = self.input[start..self.position].to_string();
+        if self.position == digits_start {
+            let span = self.span_from(start, start_line, start_column);
+            self.logger.report(
+                Message::MalformedNumericLiteral {
+                    literal: literal.clone(),
+                },
+                span,
+            );
+        }
 
-            if current_char == '.' {
-                if has_decimal {
-                    break;
+        literal
+    }
+
+    /// Scans a numeric literal starting at the current position: a
+    /// `0x`/`0b`/`0o`-prefixed integer, or a decimal integer/float that may
+    /// carry a scientific-notation exponent (`1.5e-10`). Malformed literals
+    /// (`0x` with no digits, `1.2.3`, a bare exponent) are still consumed in
+    /// full but reported as a `MalformedNumericLiteral` diagnostic.
+    fn get_next_number(&mut self, start: usize, start_line: usize, start_column: usize) -> (TokenType, String) {
+        if self.peek() == Some('0') {
+            self.bump();
+            match self.peek() {
+                Some('x') | Some('X') => {
+                    self.bump();
+                    let literal = self.finish_radix_literal(
+                        start,
+                        start_line,
+                        start_column,
+                        |c| c.is_ascii_hexdigit(),
+                    );
+                    return (TokenType::HexLiteral, literal);
+                }
+                Some('b') | Some('B') => {
+                    self.bump();
+                    let literal = self.finish_radix_literal(
+                        start,
+                        start_line,
+                        start_column,
+                        |c| c == '0' || c == '1',
+                    );
+                    return (TokenType::BinLiteral, literal);
                 }
-                has_decimal = true;
-            } else if !LexicalAnalyzer::is_digit(current_char) {
+                Some('o') | Some('O') => {
+                    self.bump();
+                    let literal = self.finish_radix_literal(
+                        start,
+                        start_line,
+                        start_column,
+                        |c| ('0'..='7').contains(&c),
+                    );
+                    return (TokenType::OctLiteral, literal);
+                }
+                _ => {} // plain number starting with '0', fall through to decimal scanning
+            }
+        } else {
+            self.bump();
+        }
+
+        let mut dot_count = 0;
+        while let Some(c) = self.peek() {
+            if c == '.' {
+                dot_count += 1;
+                self.bump();
+            } else if LexicalAnalyzer::is_digit(c) {
+                self.bump();
+            } else {
                 break;
             }
-            self.position += 1;
         }
 
-        self.input[start..self.position].to_string()
+        let mut has_exponent = false;
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            has_exponent = true;
+            self.bump();
+            if matches!(self.peek(), Some('+') | Some('-')) {
+                self.bump();
+            }
+            let exponent_digits_start = self.position;
+            while let Some(c) = self.peek() {
+                if LexicalAnalyzer::is_digit(c) {
+                    self.bump();
+                } else {
+                    break;
+                }
+            }
+            if self.position == exponent_digits_start {
+                let span = self.span_from(start, start_line, start_column);
+                let literal = self.input[start..self.position].to_string();
+                self.logger
+                    .report(Message::MalformedNumericLiteral { literal }, span);
+            }
+        }
+
+        let literal = self.input[start..self.position].to_string();
+        if dot_count > 1 {
+            let span = self.span_from(start, start_line, start_column);
+            self.logger.report(
+                Message::MalformedNumericLiteral {
+                    literal: literal.clone(),
+                },
+                span,
+            );
+        }
+
+        let token_type = if dot_count > 0 || has_exponent {
+            TokenType::FloatLiteral
+        } else {
+            TokenType::IntegerLiteral
+        };
+
+        (token_type, literal)
     }
 
-    pub fn tokenize(&mut self) -> Vec<Token> {
-        let mut tokens = Vec::new();
+    /// Scans a `"..."` string literal, having already consumed the opening
+    /// quote, interpreting `\n`, `\t`, `\\`, and `\"` escapes. If the input
+    /// ends before a closing quote is found, reports an `UnclosedStringLiteral`
+    /// diagnostic and returns whatever was scanned so far.
+    fn get_next_string(&mut self, start: usize, start_line: usize, start_column: usize) -> String {
+        let mut value = String::new();
 
-        while self.position < self.input.len() {
-            let current_char = self.input.chars().nth(self.position).unwrap();
+        loop {
+            match self.bump() {
+                Some('"') => break,
+                Some('\\') => match self.bump() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('\\') => value.push('\\'),
+                    Some('"') => value.push('"'),
+                    Some(other) => value.push(other),
+                    None => {
+                        let span = self.span_from(start, start_line, start_column);
+                        self.logger.report(Message::UnclosedStringLiteral, span);
+                        break;
+                    }
+                },
+                Some(c) => value.push(c),
+                None => {
+                    let span = self.span_from(start, start_line, start_column);
+                    self.logger.report(Message::UnclosedStringLiteral, span);
+                    break;
+                }
+            }
+        }
 
+        value
+    }
+
+    /// Lexes the full input, returning the token stream alongside every
+    /// diagnostic collected along the way.
+    pub fn tokenize(&mut self) -> (Vec<Token>, Vec<Diagnostic>) {
+        let mut tokens = Vec::new();
+
+        while let Some(current_char) = self.peek() {
             // Skip whitespace
             if LexicalAnalyzer::is_whitespace(current_char) {
-                if current_char == '\n' {
-                    self.line += 1; // Increment line number on newline
-                }
-                self.position += 1;
+                self.bump();
                 continue;
             }
 
+            let start = self.position;
+            let start_line = self.line;
+            let start_column = self.column;
+
             // Identify keywords or identifiers
             if LexicalAnalyzer::is_alpha(current_char) {
                 let word = self.get_next_word();
-                match self.keywords.get(&word) {
-                    Some(token_type) => {
-                        let token = Token::new(token_type.clone(), word, self.line);
-                        tokens.push(token);
+                let span = self.span_from(start, start_line, start_column);
+                match lookup_keyword(&word) {
+                    Some(kw) => tokens.push(Token::new(TokenType::Keyword(kw), word, span)),
+                    None => tokens.push(Token::new(TokenType::Identifier, word, span)),
+                }
+                continue;
+            }
+
+            // Identify integer, float, hex, binary, and octal literals
+            if LexicalAnalyzer::is_digit(current_char) {
+                let (token_type, number) = self.get_next_number(start, start_line, start_column);
+                let span = self.span_from(start, start_line, start_column);
+                tokens.push(Token::new(token_type, number, span));
+                continue;
+            }
+
+            // Identify string literals
+            if current_char == '"' {
+                self.bump(); // consume opening quote
+                let value = self.get_next_string(start, start_line, start_column);
+                let span = self.span_from(start, start_line, start_column);
+                tokens.push(Token::new(TokenType::StringLiteral, value, span));
+                continue;
+            }
+
+            // Comments and the `/`/`/=` operators all start with '/', so they
+            // have to be disambiguated together rather than in the generic
+            // operator branch below.
+            if current_char == '/' {
+                self.bump(); // consume the first '/'
+
+                match self.peek() {
+                    Some('/') => {
+                        self.bump(); // consume the second '/'
+                        if self.peek() == Some('/') {
+                            // `///` doc comment: keep the rest of the line as its content.
+                            self.bump();
+                            let content_start = self.position;
+                            while let Some(c) = self.peek() {
+                                if c == '\n' {
+                                    break;
+                                }
+                                self.bump();
+                            }
+                            let content = self.input[content_start..self.position].trim().to_string();
+                            let span = self.span_from(start, start_line, start_column);
+                            tokens.push(Token::new(TokenType::DocComment, content, span));
+                        } else {
+                            // `//` line comment: discard up to (not including) the newline.
+                            while let Some(c) = self.peek() {
+                                if c == '\n' {
+                                    break;
+                                }
+                                self.bump();
+                            }
+                        }
                     }
-                    None => {
-                        let token = Token::new(TokenType::Identifier, word, self.line);
-                        tokens.push(token);
+                    Some('*') => {
+                        self.bump(); // consume '*'
+                        let mut closed = false;
+                        while let Some(c) = self.bump() {
+                            if c == '*' && self.peek() == Some('/') {
+                                self.bump();
+                                closed = true;
+                                break;
+                            }
+                        }
+                        if !closed {
+                            let span = self.span_from(start, start_line, start_column);
+                            self.logger.report(Message::UnterminatedBlockComment, span);
+                        }
+                    }
+                    _ => {
+                        let mut op = "/".to_string();
+                        let compound = matches!(self.peek(), Some('='));
+                        if compound {
+                            op.push(self.bump().unwrap());
+                        }
+                        let span = self.span_from(start, start_line, start_column);
+                        tokens.push(Token::new(operator_token_type('/', compound), op, span));
                     }
                 }
+                continue;
             }
-            // Identify integer or float literals
-            else if LexicalAnalyzer::is_digit(current_char) {
-                let number = self.get_next_number();
-                let token_type = if number.contains('.') {
-                    TokenType::FloatLiteral
-                } else {
-                    TokenType::IntegerLiteral
-                };
-                tokens.push(Token::new(token_type, number, self.line));
-            }
+
             // Identify operators
-            else if "+-*/=!<>".contains(current_char) {
-                let mut op = current_char.to_string();
-                self.position += 1;
+            if "+-*=!<>".contains(current_char) {
+                let c = self.bump().unwrap();
+                let mut op = c.to_string();
 
                 // Check for compound operators
-                if self.position < self.input.len() {
-                    let next_char = self.input.chars().nth(self.position).unwrap();
-                    if next_char == '=' {
-                        op.push(next_char);
-                        self.position += 1;
-                    }
+                let compound = matches!(self.peek(), Some('='));
+                if compound {
+                    op.push(self.bump().unwrap());
                 }
 
-                tokens.push(Token::new(TokenType::Operator, op, self.line));
+                let span = self.span_from(start, start_line, start_column);
+                tokens.push(Token::new(operator_token_type(c, compound), op, span));
+                continue;
             }
+
             // Identify punctuators
-            else if "(){};".contains(current_char) {
-                tokens.push(Token::new(
-                    TokenType::Punctuator,
-                    current_char.to_string(),
-                    self.line,
-                ));
-                self.position += 1;
+            if "(){};".contains(current_char) {
+                let c = self.bump().unwrap();
+                let span = self.span_from(start, start_line, start_column);
+                tokens.push(Token::new(punctuator_token_type(c), c.to_string(), span));
+                continue;
             }
+
             // Handle unknown characters
-            else {
-                tokens.push(Token::new(
-                    TokenType::Unknown,
-                    current_char.to_string(),
-                    self.line,
-                ));
-                self.position += 1;
-            }
+            let unknown = self.bump().unwrap();
+            let span = self.span_from(start, start_line, start_column);
+            self.logger
+                .report(Message::UnexpectedCharacter(unknown), span);
+            tokens.push(Token::new(TokenType::Unknown, unknown.to_string(), span));
         }
 
-        tokens
+        (tokens, self.logger.diagnostics().to_vec())
     }
 }
 
 pub fn get_token_type_name(token_type: &TokenType) -> &str {
     match token_type {
-        TokenType::Keyword => "KEYWORD",
+        TokenType::Keyword(_) => "KEYWORD",
         TokenType::Identifier => "IDENTIFIER",
         TokenType::IntegerLiteral => "INTEGER_LITERAL",
         TokenType::FloatLiteral => "FLOAT_LITERAL",
-        TokenType::Operator => "OPERATOR",
-        TokenType::Punctuator => "PUNCTUATOR",
+        TokenType::HexLiteral => "HEX_LITERAL",
+        TokenType::BinLiteral => "BIN_LITERAL",
+        TokenType::OctLiteral => "OCT_LITERAL",
+        TokenType::StringLiteral => "STRING_LITERAL",
+        TokenType::DocComment => "DOC_COMMENT",
+        TokenType::Plus => "PLUS",
+        TokenType::PlusEq => "PLUS_EQ",
+        TokenType::Minus => "MINUS",
+        TokenType::MinusEq => "MINUS_EQ",
+        TokenType::Star => "STAR",
+        TokenType::StarEq => "STAR_EQ",
+        TokenType::Slash => "SLASH",
+        TokenType::SlashEq => "SLASH_EQ",
+        TokenType::Eq => "EQ",
+        TokenType::EqEq => "EQ_EQ",
+        TokenType::Bang => "BANG",
+        TokenType::BangEq => "BANG_EQ",
+        TokenType::Lt => "LT",
+        TokenType::LtEq => "LT_EQ",
+        TokenType::Gt => "GT",
+        TokenType::GtEq => "GT_EQ",
+        TokenType::LParen => "LPAREN",
+        TokenType::RParen => "RPAREN",
+        TokenType::LBrace => "LBRACE",
+        TokenType::RBrace => "RBRACE",
+        TokenType::Semicolon => "SEMICOLON",
         TokenType::Unknown => "UNKNOWN",
     }
 }
@@ -209,9 +600,11 @@ pub fn get_token_type_name(token_type: &TokenType) -> &str {
 pub fn print_tokens(tokens: &[Token]) {
     for token in tokens {
         println!(
-            "Type: {}, Value: {}",
+            "Type: {}, Value: {} ({}:{})",
             get_token_type_name(&token.token_type),
-            token.value
+            token.value,
+            token.span.line,
+            token.span.column,
         );
     }
 }